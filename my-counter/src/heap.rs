@@ -0,0 +1,13 @@
+// Only installed on SBF; on the host this is compiled for type-checking alone.
+#![cfg_attr(not(target_os = "solana"), allow(dead_code))]
+
+use solana_program::entrypoint::{BumpAllocator, HEAP_START_ADDRESS};
+
+use crate::HEAP_FRAME_BYTES;
+
+// Same bump allocator the entrypoint macro installs, sized to the requested frame.
+#[cfg_attr(target_os = "solana", global_allocator)]
+static ALLOCATOR: BumpAllocator = BumpAllocator {
+    start: HEAP_START_ADDRESS as usize,
+    len: HEAP_FRAME_BYTES as usize,
+};
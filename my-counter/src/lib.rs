@@ -1 +1,18 @@
+#[cfg(feature = "custom-heap")]
+mod heap;
+#[cfg(any(test, feature = "custom-panic"))]
+mod panic;
 
+/// Heap frame the program allocates from when built with `custom-heap`.
+/// The bump allocator hands out memory from the top of this frame down, so
+/// even the first allocation lies outside the default 32 KiB heap. Every
+/// instruction to a `custom-heap` build must be sent with
+/// `ComputeBudgetInstruction::request_heap_frame(HEAP_FRAME_BYTES)`,
+/// otherwise any allocation faults.
+pub const HEAP_FRAME_BYTES: u32 = 256 * 1024;
+
+/// Prefix of the log line written by the `custom-panic` handler. A panic
+/// handler cannot set an exit code on SBF, so the transaction fails with the
+/// runtime's generic panic error; clients find the panic location by
+/// scanning the transaction logs for this tag.
+pub const PANIC_LOG_TAG: &str = "Program panic:";
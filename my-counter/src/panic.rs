@@ -0,0 +1,68 @@
+// The handler is only installed on SBF; on the host the buffer is compiled for tests.
+#![cfg_attr(not(target_os = "solana"), allow(dead_code))]
+
+use core::fmt::{self, Write};
+
+const LOG_BUF_LEN: usize = 256;
+
+// Fixed stack buffer so the handler still works when the heap is exhausted.
+// Writes past the end are dropped rather than reported as errors.
+struct LogBuf {
+    buf: [u8; LOG_BUF_LEN],
+    len: usize,
+}
+
+impl LogBuf {
+    fn new() -> Self {
+        LogBuf {
+            buf: [0; LOG_BUF_LEN],
+            len: 0,
+        }
+    }
+
+    // Truncation may split a UTF-8 character, so keep only the valid prefix.
+    fn as_str(&self) -> &str {
+        match core::str::from_utf8(&self.buf[..self.len]) {
+            Ok(text) => text,
+            Err(e) => core::str::from_utf8(&self.buf[..e.valid_up_to()]).unwrap_or_default(),
+        }
+    }
+}
+
+impl Write for LogBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let room = self.buf.len() - self.len;
+        let n = s.len().min(room);
+        self.buf[self.len..self.len + n].copy_from_slice(&s.as_bytes()[..n]);
+        self.len += n;
+        Ok(())
+    }
+}
+
+#[cfg(all(feature = "custom-panic", target_os = "solana"))]
+#[no_mangle]
+fn custom_panic(info: &core::panic::PanicInfo<'_>) {
+    let mut log = LogBuf::new();
+    let _ = write!(log, "{} {}", crate::PANIC_LOG_TAG, info);
+    solana_program::log::sol_log(log.as_str());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_message_is_cut_at_buffer_length() {
+        let mut log = LogBuf::new();
+        write!(log, "{}", "a".repeat(300)).unwrap();
+        assert_eq!(log.as_str(), "a".repeat(LOG_BUF_LEN));
+    }
+
+    #[test]
+    fn split_multibyte_char_falls_back_to_valid_prefix() {
+        let mut log = LogBuf::new();
+        write!(log, "{}é", "a".repeat(LOG_BUF_LEN - 1)).unwrap();
+        assert_eq!(log.len, LOG_BUF_LEN);
+        assert_eq!(log.as_str(), "a".repeat(LOG_BUF_LEN - 1));
+    }
+}